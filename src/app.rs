@@ -1,6 +1,15 @@
-use std::time::Duration;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use mpris::{LoopStatus, Metadata, PlaybackStatus, PlayerFinder};
+use mpris::{Event, LoopStatus, Metadata, PlaybackStatus, Player, PlayerFinder};
+use ratatui::layout::Rect;
+
+use crate::art::{self, AlbumArt, GraphicsProtocol};
+use crate::event::{self, AppEvent};
+use crate::lyrics::{self, LyricLine};
+use crate::queue::{self, Queue, QueueTrack};
 
 pub struct App {
     pub running: bool,
@@ -15,11 +24,45 @@ pub struct App {
     pub volume: f64,
     pub loop_status: String,
     pub shuffle: bool,
-    pub tick_count: u64,
+    pub lyrics: Vec<LyricLine>,
+    pub active_lyric: Option<usize>,
+    pub show_lyrics: bool,
+    lyrics_key: Option<String>,
+    pub queue: Vec<QueueTrack>,
+    pub queue_supported: bool,
+    pub queue_selected: usize,
+    pub queue_focused: bool,
+    pub art_enabled: bool,
+    art_protocol: GraphicsProtocol,
+    art_url: Option<String>,
+    pub art: Option<AlbumArt>,
+    /// Widget rects stashed by `ui::draw` so mouse clicks can be translated
+    /// back into the control they landed on.
+    pub tabs_rect: Rect,
+    pub progress_rect: Rect,
+    pub volume_rect: Rect,
+
+    /// The currently selected player, cached instead of re-resolved on
+    /// every command.
+    player: Option<Player>,
+    /// Anchor for interpolating `position` between signals: the last
+    /// position read from the player, and the `Instant` it was observed.
+    /// `tick()` advances from here while playing, instead of storing a
+    /// `ProgressTracker`, which borrows from the `Player` it tracks and so
+    /// can't live alongside `player: Option<Player>` in the same struct.
+    position_anchor: Duration,
+    position_anchor_at: Instant,
+    /// Bumped each time the selected player changes, so a stale background
+    /// watcher (still blocked on the *previous* player's D-Bus events) can
+    /// recognize it's been superseded and exit instead of being force-killed.
+    generation: Arc<AtomicU64>,
+    watch_tx: Sender<AppEvent>,
+    seek_step: Duration,
+    volume_step: f64,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(watch_tx: Sender<AppEvent>, seek_step: Duration, volume_step: f64) -> Self {
         let mut app = Self {
             running: true,
             player_names: Vec::new(),
@@ -33,26 +76,52 @@ impl App {
             volume: 0.0,
             loop_status: String::from("None"),
             shuffle: false,
-            tick_count: 0,
+            lyrics: Vec::new(),
+            active_lyric: None,
+            show_lyrics: true,
+            lyrics_key: None,
+            queue: Vec::new(),
+            queue_supported: false,
+            queue_selected: 0,
+            queue_focused: false,
+            art_enabled: true,
+            art_protocol: art::detect_protocol(),
+            art_url: None,
+            art: None,
+            tabs_rect: Rect::default(),
+            progress_rect: Rect::default(),
+            volume_rect: Rect::default(),
+            player: None,
+            position_anchor: Duration::ZERO,
+            position_anchor_at: Instant::now(),
+            generation: Arc::new(AtomicU64::new(0)),
+            watch_tx,
+            seek_step,
+            volume_step,
         };
         app.refresh_players();
-        app.refresh_state();
         app
     }
 
+    /// Re-enumerates players. Called on startup and whenever a
+    /// `NameOwnerChanged` signal reports an MPRIS name appearing or
+    /// disappearing, rather than on every tick.
     pub fn refresh_players(&mut self) {
         let Ok(finder) = PlayerFinder::new() else {
             self.player_names.clear();
+            self.connect_current_player();
             return;
         };
         let Ok(players) = finder.find_all() else {
             self.player_names.clear();
+            self.connect_current_player();
             return;
         };
         let names: Vec<String> = players.iter().map(|p| p.identity().to_string()).collect();
         if names.is_empty() {
             self.player_names.clear();
             self.selected_player = 0;
+            self.connect_current_player();
             return;
         }
         // Preserve selection if possible
@@ -68,16 +137,103 @@ impl App {
         if self.selected_player >= self.player_names.len() {
             self.selected_player = 0;
         }
+        self.connect_current_player();
+    }
+
+    /// Resolves the selected player, caches it, loads its full state once,
+    /// and (re)spawns the background watcher that will keep it up to date
+    /// via `PropertiesChanged`/`Seeked` signals from here on.
+    fn connect_current_player(&mut self) {
+        self.player = self.find_player_by_name();
+
+        if self.player.is_none() {
+            self.clear_track_info();
+            return;
+        }
+
+        // Bumped before `refresh_state` so its background queue fetch is
+        // tagged with the generation it belongs to, same as the watcher.
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        self.refresh_state(generation);
+
+        // `mpris::Player` wraps a connection handle that isn't `Send`, so it
+        // can't be moved into the watcher thread directly; the watcher does
+        // its own lookup by name instead.
+        let Some(name) = self.player_names.get(self.selected_player).cloned() else {
+            return;
+        };
+        event::spawn_player_watcher(
+            name,
+            self.watch_tx.clone(),
+            generation,
+            Arc::clone(&self.generation),
+        );
+    }
+
+    fn find_player_by_name(&self) -> Option<Player> {
+        let target = self.player_names.get(self.selected_player)?;
+        let finder = PlayerFinder::new().ok()?;
+        let players = finder.find_all().ok()?;
+        players.into_iter().find(|p| p.identity() == target)
     }
 
-    pub fn refresh_state(&mut self) {
-        let Some(player) = self.find_current_player() else {
+    /// Loads everything about the current track: called once per player
+    /// connection rather than every tick, with steady-state updates now
+    /// arriving via `handle_player_event`. `generation` tags the queue
+    /// fetch it kicks off so a stale reply can be told apart from the
+    /// current player's once it lands.
+    fn refresh_state(&mut self, generation: u64) {
+        // Read everything off `player` first and drop the borrow before any
+        // `&mut self` call (`apply_metadata`, `set_position`) below — the two
+        // can't be interleaved while `player` is still borrowed.
+        let Some(player) = self.player.as_ref() else {
             self.clear_track_info();
             return;
         };
 
-        // Metadata
         let meta = player.get_metadata().unwrap_or_else(|_| Metadata::new(""));
+        let position = player.get_position().unwrap_or(Duration::ZERO);
+        let playback_status = match player.get_playback_status() {
+            Ok(PlaybackStatus::Playing) => "Playing".to_string(),
+            Ok(PlaybackStatus::Paused) => "Paused".to_string(),
+            _ => "Stopped".to_string(),
+        };
+        let volume = player.get_volume().unwrap_or(0.0);
+        let loop_status = match player.get_loop_status() {
+            Ok(LoopStatus::None) => "Off".to_string(),
+            Ok(LoopStatus::Track) => "Track".to_string(),
+            Ok(LoopStatus::Playlist) => "Playlist".to_string(),
+            Err(_) => "N/A".to_string(),
+        };
+        let shuffle = player.get_shuffle().unwrap_or(false);
+        let bus_name = player.bus_name().to_string();
+
+        self.apply_metadata(&meta);
+        self.set_position(position);
+        self.playback_status = playback_status;
+        self.volume = volume;
+        self.loop_status = loop_status;
+        self.shuffle = shuffle;
+
+        // `queue::fetch` opens its own D-Bus connection and blocks on a
+        // method call with up to half a second of timeout; run it in the
+        // background and apply the result via `handle_queue_loaded` instead
+        // of freezing redraws and input on every track change.
+        event::spawn_queue_fetch(bus_name, generation, self.watch_tx.clone());
+    }
+
+    /// Sets `position` and resets the interpolation anchor used by `tick()`.
+    fn set_position(&mut self, position: Duration) {
+        self.position = position;
+        self.position_anchor = position;
+        self.position_anchor_at = Instant::now();
+    }
+
+    /// Applies a new track's metadata: title/artist/album/duration plus the
+    /// lyrics and art re-parse/re-decode, both of which are keyed off the
+    /// track identity so they don't redo work for an unrelated property
+    /// change on the same track.
+    fn apply_metadata(&mut self, meta: &Metadata) {
         self.title = meta.title().unwrap_or("Unknown").to_string();
         self.artist = meta
             .artists()
@@ -86,99 +242,160 @@ impl App {
         self.album = meta.album_name().unwrap_or("Unknown").to_string();
         self.duration = meta.length().unwrap_or(Duration::ZERO);
 
-        // Position
-        self.position = player.get_position().unwrap_or(Duration::ZERO);
+        let url = meta.url().map(|u| u.to_string());
+        let key = format!("{}::{}", self.title, url.as_deref().unwrap_or(""));
+        if self.lyrics_key.as_deref() != Some(key.as_str()) {
+            self.lyrics = lyrics::load_lyrics(meta, url.as_deref());
+            self.active_lyric = None;
+            self.lyrics_key = Some(key);
+        }
 
-        // Playback status
-        self.playback_status = match player.get_playback_status() {
-            Ok(PlaybackStatus::Playing) => "Playing".to_string(),
-            Ok(PlaybackStatus::Paused) => "Paused".to_string(),
-            _ => "Stopped".to_string(),
-        };
+        let art_url = meta.art_url().map(|u| u.to_string());
+        if art_url != self.art_url {
+            self.art_url = art_url.clone();
+            self.art = None;
+            // `art::load` does a synchronous HTTP fetch for remote art, which
+            // would block every redraw and key/mouse handler until it
+            // returns; fetch on a background thread and deliver the result
+            // through the event channel instead.
+            if self.art_enabled {
+                if let Some(url) = art_url {
+                    event::spawn_art_load(
+                        url,
+                        self.art_protocol,
+                        art::DEFAULT_CELL_PX,
+                        self.watch_tx.clone(),
+                    );
+                }
+            }
+        }
+    }
 
-        // Volume
-        self.volume = player.get_volume().unwrap_or(0.0);
+    /// Applies the result of a background album-art fetch spawned from
+    /// `apply_metadata`, discarding it if the track has since moved on and
+    /// `art_url` no longer matches.
+    pub fn handle_art_loaded(&mut self, url: String, art: Option<AlbumArt>) {
+        if self.art_url.as_deref() == Some(url.as_str()) {
+            self.art = art;
+        }
+    }
 
-        // Loop status
-        self.loop_status = match player.get_loop_status() {
-            Ok(LoopStatus::None) => "Off".to_string(),
-            Ok(LoopStatus::Track) => "Track".to_string(),
-            Ok(LoopStatus::Playlist) => "Playlist".to_string(),
-            Err(_) => "N/A".to_string(),
-        };
+    /// Applies the result of a background TrackList fetch spawned from
+    /// `refresh_state`/`handle_player_event`, discarding it if the selected
+    /// player has since changed (the generation counter will have moved on).
+    pub fn handle_queue_loaded(&mut self, generation: u64, queue: Queue) {
+        if self.generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+        self.queue_supported = queue.supported;
+        self.queue = queue.tracks;
+        if self.queue_selected >= self.queue.len() {
+            self.queue_selected = self.queue.len().saturating_sub(1);
+        }
+    }
 
-        // Shuffle
-        self.shuffle = player.get_shuffle().unwrap_or(false);
+    /// Applies a `PropertiesChanged`/`Seeked` signal from the background
+    /// watcher directly to state, instead of re-querying the player.
+    pub fn handle_player_event(&mut self, event: Event) {
+        if self.player.is_none() {
+            return;
+        }
+        match event {
+            Event::Playing => self.playback_status = "Playing".to_string(),
+            Event::Paused => self.playback_status = "Paused".to_string(),
+            Event::Stopped => self.playback_status = "Stopped".to_string(),
+            Event::VolumeChanged(vol) => self.volume = vol,
+            Event::LoopingChanged(status) => {
+                self.loop_status = match status {
+                    LoopStatus::None => "Off".to_string(),
+                    LoopStatus::Track => "Track".to_string(),
+                    LoopStatus::Playlist => "Playlist".to_string(),
+                };
+            }
+            Event::ShuffleToggled(on) => self.shuffle = on,
+            Event::Seeked { position_in_us } => {
+                self.set_position(Duration::from_micros(position_in_us));
+            }
+            Event::TrackChanged(meta) => {
+                self.apply_metadata(&meta);
+                let bus_name = self.player.as_ref().map(|p| p.bus_name().to_string());
+                if let Some(bus_name) = bus_name {
+                    let generation = self.generation.load(Ordering::SeqCst);
+                    event::spawn_queue_fetch(bus_name, generation, self.watch_tx.clone());
+                }
+                self.queue_selected = 0;
+            }
+            Event::PlayerShutDown => self.connect_current_player(),
+            _ => {}
+        }
     }
 
     fn clear_track_info(&mut self) {
+        self.art_url = None;
+        self.art = None;
+        self.queue.clear();
+        self.queue_supported = false;
+        self.queue_selected = 0;
         self.title.clear();
         self.artist.clear();
         self.album.clear();
-        self.position = Duration::ZERO;
+        self.set_position(Duration::ZERO);
         self.duration = Duration::ZERO;
         self.playback_status = "Stopped".to_string();
         self.volume = 0.0;
         self.loop_status = "N/A".to_string();
         self.shuffle = false;
-    }
-
-    fn find_current_player(&self) -> Option<mpris::Player> {
-        if self.player_names.is_empty() {
-            return None;
-        }
-        let target = &self.player_names[self.selected_player];
-        let finder = PlayerFinder::new().ok()?;
-        let players = finder.find_all().ok()?;
-        players.into_iter().find(|p| p.identity() == target)
+        self.lyrics.clear();
+        self.active_lyric = None;
+        self.lyrics_key = None;
     }
 
     pub fn toggle_play_pause(&self) {
-        if let Some(player) = self.find_current_player() {
+        if let Some(player) = &self.player {
             let _ = player.play_pause();
         }
     }
 
     pub fn next_track(&self) {
-        if let Some(player) = self.find_current_player() {
+        if let Some(player) = &self.player {
             let _ = player.next();
         }
     }
 
     pub fn prev_track(&self) {
-        if let Some(player) = self.find_current_player() {
+        if let Some(player) = &self.player {
             let _ = player.previous();
         }
     }
 
     pub fn volume_up(&self) {
-        if let Some(player) = self.find_current_player() {
-            let vol = (self.volume + 0.05).min(1.0);
+        if let Some(player) = &self.player {
+            let vol = (self.volume + self.volume_step).min(1.0);
             let _ = player.set_volume(vol);
         }
     }
 
     pub fn volume_down(&self) {
-        if let Some(player) = self.find_current_player() {
-            let vol = (self.volume - 0.05).max(0.0);
+        if let Some(player) = &self.player {
+            let vol = (self.volume - self.volume_step).max(0.0);
             let _ = player.set_volume(vol);
         }
     }
 
     pub fn seek_forward(&self) {
-        if let Some(player) = self.find_current_player() {
-            let _ = player.seek_forwards(&Duration::from_secs(5));
+        if let Some(player) = &self.player {
+            let _ = player.seek_forwards(&self.seek_step);
         }
     }
 
     pub fn seek_backward(&self) {
-        if let Some(player) = self.find_current_player() {
-            let _ = player.seek_backwards(&Duration::from_secs(5));
+        if let Some(player) = &self.player {
+            let _ = player.seek_backwards(&self.seek_step);
         }
     }
 
     pub fn cycle_loop(&self) {
-        if let Some(player) = self.find_current_player() {
+        if let Some(player) = &self.player {
             let next = match player.get_loop_status() {
                 Ok(LoopStatus::None) => LoopStatus::Track,
                 Ok(LoopStatus::Track) => LoopStatus::Playlist,
@@ -190,15 +407,67 @@ impl App {
     }
 
     pub fn toggle_shuffle(&self) {
-        if let Some(player) = self.find_current_player() {
+        if let Some(player) = &self.player {
             let _ = player.set_shuffle(!self.shuffle);
         }
     }
 
+    /// Seeks to the fractional x-offset of a click within `self.progress_rect`.
+    pub fn seek_to_click(&mut self, column: u16) {
+        let inner = self.progress_rect.inner(ratatui::layout::Margin::new(1, 1));
+        if inner.width == 0 || self.duration.is_zero() {
+            return;
+        }
+        let offset = column.saturating_sub(inner.x).min(inner.width.saturating_sub(1));
+        let ratio = offset as f64 / inner.width.max(1) as f64;
+        let target = Duration::from_secs_f64(self.duration.as_secs_f64() * ratio.clamp(0.0, 1.0));
+        let Some(player) = &self.player else {
+            return;
+        };
+        let Ok(meta) = player.get_metadata() else {
+            return;
+        };
+        let Some(track_id) = meta.track_id() else {
+            return;
+        };
+        let _ = player.set_position(track_id, &target);
+    }
+
+    /// Sets volume proportionally to the fractional x-offset of a click
+    /// within `self.volume_rect`.
+    pub fn set_volume_from_click(&self, column: u16) {
+        if self.volume_rect.width == 0 {
+            return;
+        }
+        let offset = column
+            .saturating_sub(self.volume_rect.x)
+            .min(self.volume_rect.width.saturating_sub(1));
+        let ratio = offset as f64 / self.volume_rect.width.max(1) as f64;
+        if let Some(player) = &self.player {
+            let _ = player.set_volume(ratio.clamp(0.0, 1.0));
+        }
+    }
+
+    /// Selects whichever player tab a click landed on, assuming equal-width
+    /// tabs across `self.tabs_rect`.
+    pub fn select_player_from_click(&mut self, column: u16) {
+        if self.player_names.is_empty() || self.tabs_rect.width == 0 {
+            return;
+        }
+        let inner_width = self.tabs_rect.width.max(1) as f64;
+        let offset = column.saturating_sub(self.tabs_rect.x) as f64;
+        let tab_width = inner_width / self.player_names.len() as f64;
+        let idx = (offset / tab_width.max(1.0)) as usize;
+        if idx < self.player_names.len() {
+            self.selected_player = idx;
+            self.connect_current_player();
+        }
+    }
+
     pub fn next_player(&mut self) {
         if !self.player_names.is_empty() {
             self.selected_player = (self.selected_player + 1) % self.player_names.len();
-            self.refresh_state();
+            self.connect_current_player();
         }
     }
 
@@ -209,15 +478,84 @@ impl App {
             } else {
                 self.selected_player - 1
             };
-            self.refresh_state();
+            self.connect_current_player();
         }
     }
 
+    /// Runs on a fixed interval purely to interpolate `position` between
+    /// signals and keep the lyrics/progress display smooth; it no longer
+    /// re-enumerates players or re-reads track metadata.
     pub fn tick(&mut self) {
-        self.tick_count += 1;
-        if self.tick_count % 20 == 0 {
-            self.refresh_players();
+        if self.playback_status == "Playing" {
+            self.position = self.position_anchor + self.position_anchor_at.elapsed();
+            if self.duration > Duration::ZERO && self.position > self.duration {
+                self.position = self.duration;
+            }
         }
-        self.refresh_state();
+        self.update_active_lyric();
+    }
+
+    pub fn toggle_lyrics(&mut self) {
+        self.show_lyrics = !self.show_lyrics;
+    }
+
+    pub fn toggle_art(&mut self) {
+        self.art_enabled = !self.art_enabled;
+        if !self.art_enabled {
+            self.art = None;
+            self.art_url = None;
+        }
+    }
+
+    pub fn toggle_queue_focus(&mut self) {
+        if self.queue_supported {
+            self.queue_focused = !self.queue_focused;
+        }
+    }
+
+    pub fn queue_up(&mut self) {
+        if !self.queue.is_empty() {
+            self.queue_selected = self.queue_selected.saturating_sub(1);
+        }
+    }
+
+    pub fn queue_down(&mut self) {
+        if !self.queue.is_empty() {
+            self.queue_selected = (self.queue_selected + 1).min(self.queue.len() - 1);
+        }
+    }
+
+    pub fn queue_activate(&self) {
+        let Some(track) = self.queue.get(self.queue_selected) else {
+            return;
+        };
+        if let Some(player) = &self.player {
+            let _ = queue::go_to(player.bus_name(), &track.id);
+        }
+    }
+
+    /// Binary-searches `self.lyrics` for the last line whose timestamp is
+    /// `<= self.position` and stores its index as the active line.
+    fn update_active_lyric(&mut self) {
+        if self.lyrics.is_empty() {
+            self.active_lyric = None;
+            return;
+        }
+
+        // A fully unsynced lyric file has every line anchored at
+        // `Duration::ZERO`; the binary search below would resolve straight
+        // to the last line and stay pinned there for the rest of playback.
+        // Scroll through those on a fixed interval instead so they still
+        // advance, just without position-accurate highlighting.
+        let synced = self.lyrics.iter().any(|(ts, _)| *ts > Duration::ZERO);
+        if !synced {
+            const UNSYNCED_LINE_SECS: u64 = 4;
+            let idx = (self.position.as_secs() / UNSYNCED_LINE_SECS) as usize;
+            self.active_lyric = Some(idx.min(self.lyrics.len() - 1));
+            return;
+        }
+
+        let after = self.lyrics.partition_point(|(ts, _)| *ts <= self.position);
+        self.active_lyric = after.checked_sub(1);
     }
 }
@@ -0,0 +1,191 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::PathBuf;
+
+use base64::Engine;
+use image::{imageops::FilterType, DynamicImage};
+
+/// Pixel dimensions used when downscaling art for the album art cell.
+pub const DEFAULT_CELL_PX: (u32, u32) = (160, 160);
+
+/// Which terminal graphics protocol to emit, detected once at startup.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+    /// No graphics protocol available; render a colored block instead.
+    None,
+}
+
+/// Detects the terminal's graphics protocol from environment hints.
+///
+/// There is no universal capability query, so this follows the same
+/// heuristic most terminal image viewers use: trust `TERM`/`TERM_PROGRAM`
+/// and `WEZTERM_EXECUTABLE`.
+pub fn detect_protocol() -> GraphicsProtocol {
+    let term_program = std::env::var("TERM_PROGRAM").unwrap_or_default();
+    let term = std::env::var("TERM").unwrap_or_default();
+
+    if term_program == "kitty" || term == "xterm-kitty" {
+        GraphicsProtocol::Kitty
+    } else if term.contains("foot") || std::env::var("WEZTERM_EXECUTABLE").is_ok() {
+        GraphicsProtocol::Sixel
+    } else {
+        GraphicsProtocol::None
+    }
+}
+
+/// A decoded and downscaled piece of cover art, cached until `art_url`
+/// changes so the 250 ms redraw stays cheap.
+pub struct AlbumArt {
+    pub url: String,
+    pub protocol: GraphicsProtocol,
+    /// Pre-rendered escape sequence (Kitty/Sixel), or `None` for the
+    /// block-placeholder fallback.
+    pub encoded: Option<String>,
+}
+
+/// Loads and encodes art for `art_url`, fetching over HTTP(S) to a cache
+/// directory keyed by URL hash, or reading directly for `file://` URLs.
+pub fn load(art_url: &str, protocol: GraphicsProtocol, cell_px: (u32, u32)) -> Option<AlbumArt> {
+    if protocol == GraphicsProtocol::None {
+        return Some(AlbumArt {
+            url: art_url.to_string(),
+            protocol,
+            encoded: None,
+        });
+    }
+
+    let bytes = fetch_bytes(art_url)?;
+    let img = image::load_from_memory(&bytes).ok()?;
+    let resized = img.resize_exact(cell_px.0.max(1), cell_px.1.max(1), FilterType::Lanczos3);
+
+    let encoded = match protocol {
+        GraphicsProtocol::Kitty => Some(encode_kitty(&resized)),
+        GraphicsProtocol::Sixel => encode_sixel(&resized),
+        GraphicsProtocol::None => None,
+    };
+
+    Some(AlbumArt {
+        url: art_url.to_string(),
+        protocol,
+        encoded,
+    })
+}
+
+fn fetch_bytes(art_url: &str) -> Option<Vec<u8>> {
+    if let Some(path) = art_url.strip_prefix("file://") {
+        return std::fs::read(path).ok();
+    }
+    if art_url.starts_with("http://") || art_url.starts_with("https://") {
+        let cache_path = cache_path_for(art_url);
+        if let Ok(cached) = std::fs::read(&cache_path) {
+            return Some(cached);
+        }
+        let bytes = ureq::get(art_url).call().ok()?.into_body().read_to_vec().ok()?;
+        if let Some(parent) = cache_path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(&cache_path, &bytes);
+        return Some(bytes);
+    }
+    None
+}
+
+fn cache_path_for(url: &str) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    let mut path = std::env::temp_dir();
+    path.push("playerctl-tui-art");
+    path.push(format!("{:016x}.img", hasher.finish()));
+    path
+}
+
+/// Emits a Kitty graphics protocol escape sequence (`a=T`, base64-chunked
+/// RGB payload) that terminals can render inline.
+fn encode_kitty(img: &DynamicImage) -> String {
+    let rgb = img.to_rgb8();
+    let (width, height) = rgb.dimensions();
+    let payload = base64::engine::general_purpose::STANDARD.encode(rgb.as_raw());
+
+    let mut out = String::new();
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(4096).collect();
+    for (idx, chunk) in chunks.iter().enumerate() {
+        let more = if idx + 1 < chunks.len() { 1 } else { 0 };
+        if idx == 0 {
+            out.push_str(&format!(
+                "\x1b_Ga=T,f=24,s={width},v={height},m={more};{}\x1b\\",
+                std::str::from_utf8(chunk).unwrap_or("")
+            ));
+        } else {
+            out.push_str(&format!(
+                "\x1b_Gm={more};{}\x1b\\",
+                std::str::from_utf8(chunk).unwrap_or("")
+            ));
+        }
+    }
+    out
+}
+
+/// Emits a Sixel escape sequence using a simple fixed 6x6x6 color cube
+/// palette. Good enough for a thumbnail-sized cover; not a general-purpose
+/// encoder. Returns `None` on a degenerate (zero-sized) image.
+fn encode_sixel(img: &DynamicImage) -> Option<String> {
+    let rgb = img.to_rgb8();
+    let (width, height) = rgb.dimensions();
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let palette_index = |r: u8, g: u8, b: u8| -> usize {
+        let bucket = |c: u8| (c as u16 * 5 / 255) as usize;
+        bucket(r) * 36 + bucket(g) * 6 + bucket(b)
+    };
+
+    let mut out = String::from("\x1bPq");
+    for c in 0..216usize {
+        let (r, g, b) = (c / 36, (c / 6) % 6, c % 6);
+        out.push_str(&format!(
+            "#{c};2;{};{};{}",
+            r * 100 / 5,
+            g * 100 / 5,
+            b * 100 / 5
+        ));
+    }
+
+    let mut y = 0u32;
+    while y < height {
+        let band_height = (height - y).min(6);
+        for color in 0..216usize {
+            let mut row = String::new();
+            let mut used = false;
+            for x in 0..width {
+                let mut bits = 0u8;
+                for dy in 0..band_height {
+                    let px = rgb.get_pixel(x, y + dy);
+                    if palette_index(px[0], px[1], px[2]) == color {
+                        bits |= 1 << dy;
+                        used = true;
+                    }
+                }
+                row.push((0x3f + bits) as char);
+            }
+            if used {
+                out.push_str(&format!("#{color}{row}$"));
+            }
+        }
+        out.push('-');
+        y += band_height;
+    }
+    out.push_str("\x1b\\");
+    Some(out)
+}
+
+/// Writes a pre-encoded escape sequence directly to the terminal, bypassing
+/// ratatui's cell buffer (graphics protocols draw over, not through, cells).
+pub fn emit(encoded: &str) {
+    let mut stdout = std::io::stdout();
+    let _ = stdout.write_all(encoded.as_bytes());
+    let _ = stdout.flush();
+}
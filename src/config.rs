@@ -0,0 +1,468 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Color;
+use serde::Deserialize;
+
+/// An action a key can be bound to. Matched against the action names used
+/// in a config file's `[keybindings]` table (e.g. `play_pause = "space"`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    PlayPause,
+    Next,
+    Prev,
+    VolumeUp,
+    VolumeDown,
+    SeekBackward,
+    SeekForward,
+    NextPlayer,
+    PrevPlayer,
+    CycleLoop,
+    ToggleShuffle,
+    ToggleLyrics,
+    ToggleQueueFocus,
+    ToggleArt,
+    QueueUp,
+    QueueDown,
+    QueueActivate,
+}
+
+/// The hardcoded defaults, as (action name, default key, action).
+const DEFAULT_BINDINGS: &[(&str, &str, Action)] = &[
+    ("quit", "q", Action::Quit),
+    ("quit", "esc", Action::Quit),
+    ("quit", "ctrl+c", Action::Quit),
+    ("play_pause", "space", Action::PlayPause),
+    ("next", "n", Action::Next),
+    ("prev", "p", Action::Prev),
+    ("volume_up", "+", Action::VolumeUp),
+    ("volume_up", "=", Action::VolumeUp),
+    ("volume_down", "-", Action::VolumeDown),
+    ("seek_backward", "left", Action::SeekBackward),
+    ("seek_forward", "right", Action::SeekForward),
+    ("next_player", "tab", Action::NextPlayer),
+    ("prev_player", "backtab", Action::PrevPlayer),
+    ("cycle_loop", "l", Action::CycleLoop),
+    ("toggle_shuffle", "s", Action::ToggleShuffle),
+    ("toggle_lyrics", "y", Action::ToggleLyrics),
+    ("toggle_queue_focus", "t", Action::ToggleQueueFocus),
+    ("toggle_art", "i", Action::ToggleArt),
+    ("queue_up", "up", Action::QueueUp),
+    ("queue_up", "k", Action::QueueUp),
+    ("queue_down", "down", Action::QueueDown),
+    ("queue_down", "j", Action::QueueDown),
+    ("queue_activate", "enter", Action::QueueActivate),
+];
+
+pub struct Theme {
+    pub fg: Color,
+    pub accent: Color,
+    pub dim: Color,
+}
+
+impl Theme {
+    fn dark() -> Self {
+        Self {
+            fg: Color::White,
+            accent: Color::Cyan,
+            dim: Color::DarkGray,
+        }
+    }
+
+    fn light() -> Self {
+        Self {
+            fg: Color::Black,
+            accent: Color::Blue,
+            dim: Color::Gray,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+pub struct Config {
+    pub keymap: HashMap<(KeyCode, KeyModifiers), Action>,
+    pub theme: Theme,
+    pub poll_interval: Duration,
+    pub seek_step: Duration,
+    pub volume_step: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let mut keymap = HashMap::new();
+        for (_, key, action) in DEFAULT_BINDINGS {
+            if let Some(parsed) = parse_key(key) {
+                keymap.insert(parsed, *action);
+            }
+        }
+        Self {
+            keymap,
+            theme: Theme::default(),
+            poll_interval: Duration::from_millis(250),
+            seek_step: Duration::from_secs(5),
+            volume_step: 0.05,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct ConfigFile {
+    #[serde(default)]
+    keybindings: HashMap<String, String>,
+    #[serde(default)]
+    theme: ThemeFile,
+    poll_ms: Option<u64>,
+    seek_step_secs: Option<u64>,
+    volume_step: Option<f64>,
+}
+
+#[derive(Deserialize, Default)]
+struct ThemeFile {
+    /// `"light"`, `"dark"`, or `"auto"` (the default) to detect the
+    /// terminal's background via an OSC 11 query.
+    mode: Option<String>,
+    fg: Option<String>,
+    accent: Option<String>,
+    dim: Option<String>,
+}
+
+/// Loads `$XDG_CONFIG_HOME/playerctl-tui/config.toml`, falling back to the
+/// hardcoded defaults for anything absent or invalid. Writes out a default
+/// config file on first run (when none exists yet).
+///
+/// Unless `[theme].mode` pins a choice, the base theme is picked by
+/// querying the terminal's background color (OSC 11) and falling back to
+/// the dark defaults if the terminal doesn't answer. Must be called after
+/// raw mode is enabled.
+pub fn load() -> Config {
+    let path = config_path();
+    let text = fs::read_to_string(&path).ok();
+    if text.is_none() && !path.exists() {
+        write_default(&path);
+    }
+
+    let file: ConfigFile = text
+        .as_deref()
+        .and_then(|t| toml::from_str(t).ok())
+        .unwrap_or_default();
+
+    let mut config = Config::default();
+
+    config.theme = match file.theme.mode.as_deref() {
+        Some("light") => Theme::light(),
+        Some("dark") => Theme::dark(),
+        _ => query_background_rgb()
+            .map(|rgb| {
+                if is_light_background(rgb) {
+                    Theme::light()
+                } else {
+                    Theme::dark()
+                }
+            })
+            .unwrap_or_default(),
+    };
+
+    for (action_name, key_str) in &file.keybindings {
+        let Some(action) = action_from_name(action_name) else {
+            continue;
+        };
+        let Some(parsed) = parse_key(key_str) else {
+            continue;
+        };
+        config.keymap.retain(|_, bound| *bound != action);
+        config.keymap.insert(parsed, action);
+    }
+
+    if let Some(fg) = file.theme.fg.as_deref().and_then(parse_color) {
+        config.theme.fg = fg;
+    }
+    if let Some(accent) = file.theme.accent.as_deref().and_then(parse_color) {
+        config.theme.accent = accent;
+    }
+    if let Some(dim) = file.theme.dim.as_deref().and_then(parse_color) {
+        config.theme.dim = dim;
+    }
+
+    if let Some(ms) = file.poll_ms {
+        config.poll_interval = Duration::from_millis(ms);
+    }
+    if let Some(secs) = file.seek_step_secs {
+        config.seek_step = Duration::from_secs(secs);
+    }
+    if let Some(step) = file.volume_step {
+        config.volume_step = step;
+    }
+
+    config
+}
+
+fn config_path() -> PathBuf {
+    let base = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join("playerctl-tui").join("config.toml")
+}
+
+fn write_default(path: &PathBuf) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(path, DEFAULT_CONFIG_TOML);
+}
+
+fn action_from_name(name: &str) -> Option<Action> {
+    DEFAULT_BINDINGS
+        .iter()
+        .find(|(action_name, _, _)| *action_name == name)
+        .map(|(_, _, action)| *action)
+}
+
+/// Parses a key name like `"ctrl+q"`, `"space"`, or `"n"` into a
+/// `(KeyCode, KeyModifiers)` pair.
+fn parse_key(s: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut parts: Vec<&str> = s.split('+').collect();
+    let mut key = parts.pop()?;
+    // A trailing "++" (or a bare "+") names the '+' key itself.
+    if key.is_empty() && parts.last() == Some(&"") {
+        parts.pop();
+        key = "+";
+    }
+
+    let mut modifiers = KeyModifiers::NONE;
+    for modifier in parts {
+        match modifier.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            _ => return None,
+        }
+    }
+
+    let code = match key.to_ascii_lowercase().as_str() {
+        "space" => KeyCode::Char(' '),
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "enter" | "return" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        other if other.chars().count() == 1 => KeyCode::Char(other.chars().next()?),
+        _ => return None,
+    };
+    Some((code, modifiers))
+}
+
+/// Sends an OSC 11 query (`\x1b]11;?\x07`) and waits briefly for the
+/// terminal's `rgb:RRRR/GGGG/BBBB` reply. Raw mode must already be enabled,
+/// and this must run before `event::spawn_input` starts its own reader —
+/// there must only ever be one reader of stdin at a time.
+///
+/// Many terminals, and anything non-interactive, never answer. A plain
+/// blocking read has no way to give up on that case without leaving a
+/// thread parked on stdin forever — which would go on to race (and
+/// sometimes win against) the real input reader for bytes the user typed
+/// during startup. So each byte is gated by a `poll(2)` with a deadline,
+/// bounding the whole query to one caller, on one thread, for a fixed time.
+fn query_background_rgb() -> Option<(u8, u8, u8)> {
+    print!("\x1b]11;?\x07");
+    io::stdout().flush().ok()?;
+
+    let stdin = io::stdin();
+    let fd = stdin.as_raw_fd();
+    let deadline = Instant::now() + Duration::from_millis(200);
+    let mut reply = Vec::new();
+
+    while reply.len() < 32 {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return None;
+        }
+        let mut pfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ready = unsafe { libc::poll(&mut pfd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            return None;
+        }
+
+        let mut byte = [0u8; 1];
+        match stdin.lock().read(&mut byte) {
+            Ok(1) => reply.push(byte[0]),
+            _ => return None,
+        }
+        if byte[0] == 0x07 || reply.ends_with(b"\x1b\\") {
+            break;
+        }
+    }
+
+    parse_osc11_reply(&reply)
+}
+
+/// Extracts the `RRRR/GGGG/BBBB` triplet from an OSC 11 reply, taking the
+/// high byte of each 16-bit channel.
+fn parse_osc11_reply(reply: &[u8]) -> Option<(u8, u8, u8)> {
+    let text = String::from_utf8_lossy(reply);
+    let triplet = &text[text.find("rgb:")? + 4..];
+    let end = triplet
+        .find(|c: char| c == '\u{7}' || c == '\u{1b}')
+        .unwrap_or(triplet.len());
+    let mut channels = triplet[..end].splitn(3, '/');
+    let channel = |s: &str| u8::from_str_radix(&s[..s.len().min(2)], 16).ok();
+    Some((
+        channel(channels.next()?)?,
+        channel(channels.next()?)?,
+        channel(channels.next()?)?,
+    ))
+}
+
+/// Approximate relative luminance (ITU-R BT.709 weights, no gamma
+/// correction) — good enough to decide between a light and dark theme.
+fn is_light_background((r, g, b): (u8, u8, u8)) -> bool {
+    let luminance = 0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64;
+    luminance > 140.0
+}
+
+/// Parses `"#rrggbb"` or a handful of named colors.
+fn parse_color(s: &str) -> Option<Color> {
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() == 6 {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            return Some(Color::Rgb(r, g, b));
+        }
+        return None;
+    }
+    match s.to_ascii_lowercase().as_str() {
+        "white" => Some(Color::White),
+        "black" => Some(Color::Black),
+        "red" => Some(Color::Red),
+        "green" => Some(Color::Green),
+        "yellow" => Some(Color::Yellow),
+        "blue" => Some(Color::Blue),
+        "magenta" => Some(Color::Magenta),
+        "cyan" => Some(Color::Cyan),
+        "gray" | "grey" => Some(Color::Gray),
+        "darkgray" | "darkgrey" => Some(Color::DarkGray),
+        _ => None,
+    }
+}
+
+const DEFAULT_CONFIG_TOML: &str = r##"# playerctl-tui configuration
+# Uncomment and edit any of the following to override the defaults.
+
+[keybindings]
+# play_pause = "space"
+# next = "n"
+# prev = "p"
+# volume_up = "+"
+# volume_down = "-"
+# seek_backward = "left"
+# seek_forward = "right"
+# next_player = "tab"
+# prev_player = "backtab"
+# cycle_loop = "l"
+# toggle_shuffle = "s"
+# toggle_lyrics = "y"
+# toggle_queue_focus = "t"
+# toggle_art = "i"
+# quit = "ctrl+q"
+
+[theme]
+# mode = "auto"  # "auto" (detect terminal background), "light", or "dark"
+# fg = "#ffffff"
+# accent = "#00ffff"
+# dim = "#808080"
+
+# poll_ms = 250
+# seek_step_secs = 5
+# volume_step = 0.05
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_key_single_char_has_no_modifiers() {
+        assert_eq!(parse_key("n"), Some((KeyCode::Char('n'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn parse_key_named_keys() {
+        assert_eq!(parse_key("space"), Some((KeyCode::Char(' '), KeyModifiers::NONE)));
+        assert_eq!(parse_key("esc"), Some((KeyCode::Esc, KeyModifiers::NONE)));
+        assert_eq!(parse_key("escape"), Some((KeyCode::Esc, KeyModifiers::NONE)));
+        assert_eq!(parse_key("tab"), Some((KeyCode::Tab, KeyModifiers::NONE)));
+        assert_eq!(parse_key("backtab"), Some((KeyCode::BackTab, KeyModifiers::NONE)));
+        assert_eq!(parse_key("enter"), Some((KeyCode::Enter, KeyModifiers::NONE)));
+        assert_eq!(parse_key("return"), Some((KeyCode::Enter, KeyModifiers::NONE)));
+        assert_eq!(parse_key("left"), Some((KeyCode::Left, KeyModifiers::NONE)));
+        assert_eq!(parse_key("right"), Some((KeyCode::Right, KeyModifiers::NONE)));
+        assert_eq!(parse_key("up"), Some((KeyCode::Up, KeyModifiers::NONE)));
+        assert_eq!(parse_key("down"), Some((KeyCode::Down, KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn parse_key_combines_multiple_modifiers_case_insensitively() {
+        assert_eq!(
+            parse_key("Ctrl+Shift+x"),
+            Some((
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))
+        );
+        assert_eq!(
+            parse_key("alt+control+shift+q"),
+            Some((
+                KeyCode::Char('q'),
+                KeyModifiers::ALT | KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_key_rejects_unknown_modifier_or_key() {
+        assert_eq!(parse_key("hyper+n"), None);
+        assert_eq!(parse_key("nope"), None);
+        assert_eq!(parse_key(""), None);
+    }
+
+    #[test]
+    fn parse_osc11_reply_extracts_rgb_with_bel_terminator() {
+        let reply = b"\x1b]11;rgb:1111/2222/3333\x07";
+        assert_eq!(parse_osc11_reply(reply), Some((0x11, 0x22, 0x33)));
+    }
+
+    #[test]
+    fn parse_osc11_reply_extracts_rgb_with_st_terminator() {
+        let reply = b"\x1b]11;rgb:ffff/0000/8080\x1b\\";
+        assert_eq!(parse_osc11_reply(reply), Some((0xff, 0x00, 0x80)));
+    }
+
+    #[test]
+    fn parse_osc11_reply_rejects_missing_rgb_prefix() {
+        assert_eq!(parse_osc11_reply(b"\x1b]11;garbage\x07"), None);
+    }
+
+    #[test]
+    fn parse_osc11_reply_rejects_incomplete_triplet() {
+        assert_eq!(parse_osc11_reply(b"\x1b]11;rgb:1111/2222\x07"), None);
+    }
+}
@@ -1,17 +1,161 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+use std::thread;
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyEvent};
+use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
+use mpris::{Event as PlayerEvent, PlayerFinder};
+
+use crate::art::{self, AlbumArt, GraphicsProtocol};
+use crate::queue::{self, Queue};
 
 pub enum AppEvent {
     Key(KeyEvent),
+    Mouse(MouseEvent),
+    /// A `PropertiesChanged`/`Seeked` signal from the currently watched
+    /// player.
+    Player(PlayerEvent),
+    /// A `NameOwnerChanged` signal reported an MPRIS name appearing or
+    /// disappearing; the player list should be re-enumerated.
+    PlayersChanged,
+    /// Fired on a fixed interval so the interpolated progress bar and
+    /// lyrics highlight keep moving between signals.
     Tick,
+    /// A background album-art fetch for `url` finished (or failed to
+    /// decode/fetch, in which case `art` is `None`).
+    ArtLoaded { url: String, art: Option<AlbumArt> },
+    /// A background TrackList fetch for the player at `generation` finished.
+    QueueLoaded { generation: u64, queue: Queue },
+}
+
+/// Reads raw terminal input on a dedicated thread and forwards key/mouse
+/// events into the app loop's channel.
+pub fn spawn_input(tx: Sender<AppEvent>) {
+    thread::spawn(move || loop {
+        match event::read() {
+            Ok(CrosstermEvent::Key(key)) => {
+                if tx.send(AppEvent::Key(key)).is_err() {
+                    return;
+                }
+            }
+            Ok(CrosstermEvent::Mouse(mouse)) => {
+                if tx.send(AppEvent::Mouse(mouse)).is_err() {
+                    return;
+                }
+            }
+            Ok(_) => {}
+            Err(_) => return,
+        }
+    });
 }
 
-pub fn poll_event(timeout: Duration) -> anyhow::Result<AppEvent> {
-    if event::poll(timeout)? {
-        if let Event::Key(key) = event::read()? {
-            return Ok(AppEvent::Key(key));
+/// Sends a `Tick` on a fixed interval.
+pub fn spawn_ticker(tx: Sender<AppEvent>, interval: Duration) {
+    thread::spawn(move || loop {
+        thread::sleep(interval);
+        if tx.send(AppEvent::Tick).is_err() {
+            return;
+        }
+    });
+}
+
+/// Watches `org.freedesktop.DBus`'s `NameOwnerChanged` signal for MPRIS
+/// players appearing or disappearing, so the app only re-enumerates
+/// players when the set actually changes.
+pub fn spawn_name_owner_watch(tx: Sender<AppEvent>) {
+    thread::spawn(move || {
+        let Ok(conn) = dbus::blocking::Connection::new_session() else {
+            return;
+        };
+        let rule = dbus::message::MatchRule::new_signal(
+            "org.freedesktop.DBus",
+            "NameOwnerChanged",
+        );
+        let result = conn.add_match(rule, move |(name, _, _): (String, String, String), _, _| {
+            if name.starts_with("org.mpris.MediaPlayer2.") {
+                let _ = tx.send(AppEvent::PlayersChanged);
+            }
+            true
+        });
+        if result.is_err() {
+            return;
+        }
+        loop {
+            if conn.process(Duration::from_millis(1000)).is_err() {
+                return;
+            }
         }
-    }
-    Ok(AppEvent::Tick)
+    });
+}
+
+/// Spawns a background watcher over the named player's `PropertiesChanged`/
+/// `Seeked` signals, forwarding each as `AppEvent::Player`.
+///
+/// `mpris::Player` wraps a D-Bus connection handle that isn't `Send`, so it
+/// can't be handed to this thread directly; the watcher re-resolves it by
+/// identity on its own instead.
+///
+/// `player.events()` blocks on D-Bus between signals, so when the selected
+/// player changes there is no clean way to interrupt an in-flight watcher;
+/// instead it's tagged with `generation` and checks `current` after every
+/// event, quietly exiting once it sees a newer generation has taken over.
+pub fn spawn_player_watcher(
+    player_name: String,
+    tx: Sender<AppEvent>,
+    generation: u64,
+    current: Arc<AtomicU64>,
+) {
+    thread::spawn(move || {
+        let Ok(finder) = PlayerFinder::new() else {
+            return;
+        };
+        let Ok(players) = finder.find_all() else {
+            return;
+        };
+        let Some(player) = players.into_iter().find(|p| p.identity() == player_name) else {
+            return;
+        };
+        let Ok(events) = player.events() else {
+            return;
+        };
+        for event in events {
+            if current.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            let Ok(event) = event else {
+                return;
+            };
+            if tx.send(AppEvent::Player(event)).is_err() {
+                return;
+            }
+        }
+    });
+}
+
+/// Fetches and decodes album art for `url` on a background thread,
+/// delivering the result as `AppEvent::ArtLoaded` instead of blocking the
+/// main loop — the HTTP(S) path in `art::load` is a synchronous request
+/// that can take a while.
+pub fn spawn_art_load(
+    url: String,
+    protocol: GraphicsProtocol,
+    cell_px: (u32, u32),
+    tx: Sender<AppEvent>,
+) {
+    thread::spawn(move || {
+        let art = art::load(&url, protocol, cell_px);
+        let _ = tx.send(AppEvent::ArtLoaded { url, art });
+    });
+}
+
+/// Fetches a player's `TrackList` queue on a background thread, delivering
+/// the result as `AppEvent::QueueLoaded` instead of blocking the main loop
+/// — `queue::fetch` opens its own D-Bus connection and blocks on a method
+/// call with up to half a second of timeout.
+pub fn spawn_queue_fetch(bus_name: String, generation: u64, tx: Sender<AppEvent>) {
+    thread::spawn(move || {
+        let queue = queue::fetch(&bus_name);
+        let _ = tx.send(AppEvent::QueueLoaded { generation, queue });
+    });
 }
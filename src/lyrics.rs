@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use mpris::Metadata;
+
+/// A single parsed lyric line: the timestamp it should appear at and its text.
+///
+/// Unsynced lines (no `[mm:ss.xx]` tag) are anchored at `Duration::ZERO` so
+/// they still render, just without position-based highlighting.
+pub type LyricLine = (Duration, String);
+
+/// Loads lyrics for the currently playing track, preferring MPRIS metadata
+/// over a sibling `.lrc` file.
+pub fn load_lyrics(meta: &Metadata, url: Option<&str>) -> Vec<LyricLine> {
+    if let Some(text) = meta.get("xesam:asText").and_then(|v| v.as_str()) {
+        return parse_lrc(text);
+    }
+    if let Some(url) = url {
+        if let Some(path) = lrc_path_for_url(url) {
+            if let Ok(text) = std::fs::read_to_string(path) {
+                return parse_lrc(&text);
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// Parses LRC-formatted lyrics into timestamped lines, sorted ascending.
+///
+/// A single text line may carry several `[mm:ss.xx]` tags (e.g. a chorus
+/// repeated at multiple points), in which case one entry is emitted per tag.
+pub fn parse_lrc(raw: &str) -> Vec<LyricLine> {
+    let mut lines = Vec::new();
+
+    for line in raw.lines() {
+        let mut rest = line;
+        let mut timestamps = Vec::new();
+        while let Some(stripped) = rest.strip_prefix('[') {
+            let Some(close) = stripped.find(']') else {
+                break;
+            };
+            match parse_timestamp(&stripped[..close]) {
+                Some(ts) => {
+                    timestamps.push(ts);
+                    rest = &stripped[close + 1..];
+                }
+                None => break,
+            }
+        }
+
+        let text = rest.trim().to_string();
+        if timestamps.is_empty() {
+            if !text.is_empty() {
+                lines.push((Duration::ZERO, text));
+            }
+        } else {
+            for ts in timestamps {
+                lines.push((ts, text.clone()));
+            }
+        }
+    }
+
+    lines.sort_by_key(|(ts, _)| *ts);
+    lines
+}
+
+/// Parses an LRC tag body (`mm:ss.xx`) into a `Duration`.
+fn parse_timestamp(tag: &str) -> Option<Duration> {
+    let (minutes, rest) = tag.split_once(':')?;
+    let (seconds, centis) = rest.split_once('.')?;
+    let minutes: u64 = minutes.parse().ok()?;
+    let seconds: u64 = seconds.parse().ok()?;
+    let centis_value: u64 = centis.parse().ok()?;
+    let frac_digits = (centis.len() as u32).min(9);
+    let nanos = centis_value * 10u64.pow(9 - frac_digits);
+    Some(Duration::from_secs(minutes * 60 + seconds) + Duration::from_nanos(nanos))
+}
+
+/// Resolves the sibling `.lrc` path for a `file://` track URL.
+fn lrc_path_for_url(url: &str) -> Option<PathBuf> {
+    let path = url.strip_prefix("file://")?;
+    Some(PathBuf::from(path).with_extension("lrc"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_timestamp_parses_minutes_seconds_centiseconds() {
+        assert_eq!(
+            parse_timestamp("03:27.50"),
+            Some(Duration::from_millis(3 * 60_000 + 27_000 + 500))
+        );
+    }
+
+    #[test]
+    fn parse_timestamp_handles_varying_fraction_widths() {
+        assert_eq!(parse_timestamp("00:01.5"), Some(Duration::from_millis(1_500)));
+        assert_eq!(parse_timestamp("00:01.500"), Some(Duration::from_millis(1_500)));
+        assert_eq!(
+            parse_timestamp("00:01.123456"),
+            Some(Duration::from_nanos(1_123_456_000))
+        );
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_malformed_tags() {
+        assert_eq!(parse_timestamp("not-a-tag"), None);
+        assert_eq!(parse_timestamp("03:27"), None);
+        assert_eq!(parse_timestamp("ab:27.50"), None);
+    }
+
+    #[test]
+    fn parse_lrc_emits_one_line_per_tag_and_sorts_ascending() {
+        let raw = "[00:02.00]second\n[00:01.00]first\n[00:00.00][00:03.00]shared";
+        let lines = parse_lrc(raw);
+        assert_eq!(
+            lines,
+            vec![
+                (Duration::from_secs(0), "shared".to_string()),
+                (Duration::from_secs(1), "first".to_string()),
+                (Duration::from_secs(2), "second".to_string()),
+                (Duration::from_secs(3), "shared".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_lrc_keeps_unsynced_lines_anchored_at_zero() {
+        let lines = parse_lrc("no tag here\n[00:05.00]tagged");
+        assert_eq!(
+            lines,
+            vec![
+                (Duration::ZERO, "no tag here".to_string()),
+                (Duration::from_secs(5), "tagged".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_lrc_skips_blank_untagged_lines() {
+        assert!(parse_lrc("\n   \n").is_empty());
+    }
+}
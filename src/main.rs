@@ -1,68 +1,157 @@
 mod app;
+mod art;
+mod config;
 mod event;
+mod lyrics;
+mod queue;
 mod ui;
 
 use std::io;
 use std::panic;
-use std::time::Duration;
+use std::sync::mpsc;
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, KeyEvent, MouseButton, MouseEvent, MouseEventKind,
+};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 use crossterm::{cursor, execute};
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 
 use app::App;
-use event::{poll_event, AppEvent};
+use config::Action;
+use event::AppEvent;
 
 fn main() -> anyhow::Result<()> {
     // Panic hook to restore terminal
     let default_hook = panic::take_hook();
     panic::set_hook(Box::new(move |info| {
         let _ = terminal::disable_raw_mode();
-        let _ = execute!(io::stderr(), LeaveAlternateScreen, cursor::Show);
+        let _ = execute!(
+            io::stderr(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            cursor::Show
+        );
         default_hook(info);
     }));
 
     // Setup terminal
     terminal::enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, cursor::Hide)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new();
+    let config = config::load();
+
+    // Key/mouse input, the periodic tick, and per-player D-Bus signal
+    // watchers all feed into one channel instead of a single `poll`-with-
+    // timeout loop, so the app reacts to whichever arrives first.
+    let (tx, rx) = mpsc::channel();
+    event::spawn_input(tx.clone());
+    event::spawn_ticker(tx.clone(), config.poll_interval);
+    event::spawn_name_owner_watch(tx.clone());
+
+    let mut app = App::new(tx, config.seek_step, config.volume_step);
 
     while app.running {
-        terminal.draw(|f| ui::draw(f, &app))?;
+        terminal.draw(|f| ui::draw(f, &mut app, &config.theme))?;
+
+        // Graphics protocols draw over the terminal cells, not through
+        // ratatui's buffer, so the escape sequence is emitted right after
+        // the frame instead of as part of a widget.
+        if let Some(art) = app.art.as_ref().filter(|_| app.art_enabled) {
+            if let Some(encoded) = &art.encoded {
+                let size = terminal.size()?;
+                let area = ratatui::layout::Rect::new(0, 0, size.width, size.height);
+                let rect = ui::art_rect(area);
+                execute!(terminal.backend_mut(), cursor::MoveTo(rect.x + 1, rect.y + 1))?;
+                art::emit(encoded);
+            }
+        }
 
-        match poll_event(Duration::from_millis(250))? {
-            AppEvent::Key(key) => handle_key(&mut app, key),
+        let Ok(event) = rx.recv() else {
+            break;
+        };
+        match event {
+            AppEvent::Key(key) => handle_key(&mut app, &config, key),
+            AppEvent::Mouse(mouse) => handle_mouse(&mut app, mouse),
+            AppEvent::Player(player_event) => app.handle_player_event(player_event),
+            AppEvent::PlayersChanged => app.refresh_players(),
             AppEvent::Tick => app.tick(),
+            AppEvent::ArtLoaded { url, art } => app.handle_art_loaded(url, art),
+            AppEvent::QueueLoaded { generation, queue } => {
+                app.handle_queue_loaded(generation, queue)
+            }
         }
     }
 
     // Restore terminal
     terminal::disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen, cursor::Show)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        cursor::Show
+    )?;
     Ok(())
 }
 
-fn handle_key(app: &mut App, key: KeyEvent) {
-    match (key.code, key.modifiers) {
-        (KeyCode::Char('c'), KeyModifiers::CONTROL) => app.running = false,
-        (KeyCode::Char('q'), _) | (KeyCode::Esc, _) => app.running = false,
-        (KeyCode::Char(' '), _) => app.toggle_play_pause(),
-        (KeyCode::Char('n'), _) => app.next_track(),
-        (KeyCode::Char('p'), _) => app.prev_track(),
-        (KeyCode::Char('+') | KeyCode::Char('='), _) => app.volume_up(),
-        (KeyCode::Char('-'), _) => app.volume_down(),
-        (KeyCode::Left, _) => app.seek_backward(),
-        (KeyCode::Right, _) => app.seek_forward(),
-        (KeyCode::Tab, _) => app.next_player(),
-        (KeyCode::BackTab, _) => app.prev_player(),
-        (KeyCode::Char('l'), _) => app.cycle_loop(),
-        (KeyCode::Char('s'), _) => app.toggle_shuffle(),
-        _ => {}
+fn handle_key(app: &mut App, config: &config::Config, key: KeyEvent) {
+    let Some(action) = config.keymap.get(&(key.code, key.modifiers)) else {
+        return;
+    };
+
+    // Queue navigation only applies while the queue panel has focus; the
+    // keys bound to it do nothing otherwise.
+    if matches!(action, Action::QueueUp | Action::QueueDown | Action::QueueActivate)
+        && !app.queue_focused
+    {
+        return;
+    }
+
+    match action {
+        Action::Quit => app.running = false,
+        Action::PlayPause => app.toggle_play_pause(),
+        Action::Next => app.next_track(),
+        Action::Prev => app.prev_track(),
+        Action::VolumeUp => app.volume_up(),
+        Action::VolumeDown => app.volume_down(),
+        Action::SeekBackward => app.seek_backward(),
+        Action::SeekForward => app.seek_forward(),
+        Action::NextPlayer => app.next_player(),
+        Action::PrevPlayer => app.prev_player(),
+        Action::CycleLoop => app.cycle_loop(),
+        Action::ToggleShuffle => app.toggle_shuffle(),
+        Action::ToggleLyrics => app.toggle_lyrics(),
+        Action::ToggleQueueFocus => app.toggle_queue_focus(),
+        Action::ToggleArt => app.toggle_art(),
+        Action::QueueUp => app.queue_up(),
+        Action::QueueDown => app.queue_down(),
+        Action::QueueActivate => app.queue_activate(),
+    }
+}
+
+fn handle_mouse(app: &mut App, mouse: MouseEvent) {
+    let is_click_or_drag = matches!(
+        mouse.kind,
+        MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left)
+    );
+    if !is_click_or_drag {
+        return;
     }
+
+    let point = (mouse.column, mouse.row);
+    if within(app.progress_rect, point) {
+        app.seek_to_click(mouse.column);
+    } else if within(app.volume_rect, point) {
+        app.set_volume_from_click(mouse.column);
+    } else if within(app.tabs_rect, point) {
+        app.select_player_from_click(mouse.column);
+    }
+}
+
+fn within(rect: ratatui::layout::Rect, (col, row): (u16, u16)) -> bool {
+    col >= rect.x && col < rect.x + rect.width && row >= rect.y && row < rect.y + rect.height
 }
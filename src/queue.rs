@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use dbus::arg::{PropMap, RefArg};
+use dbus::blocking::stdintf::org_freedesktop_dbus::Properties;
+use dbus::blocking::{Connection, Proxy};
+use dbus::Path as DbusPath;
+
+const TIMEOUT: Duration = Duration::from_millis(500);
+const TRACK_LIST_IFACE: &str = "org.mpris.MediaPlayer2.TrackList";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+
+/// One entry in a player's `org.mpris.MediaPlayer2.TrackList`.
+pub struct QueueTrack {
+    pub id: String,
+    pub title: String,
+    pub artist: String,
+}
+
+/// The current queue for a player, or a note that it doesn't support one.
+pub struct Queue {
+    pub supported: bool,
+    pub tracks: Vec<QueueTrack>,
+}
+
+impl Queue {
+    pub fn unsupported() -> Self {
+        Self {
+            supported: false,
+            tracks: Vec::new(),
+        }
+    }
+}
+
+/// Fetches the current TrackList for `bus_name`. Many players don't
+/// implement `TrackList` at all, in which case `Queue::supported` is false
+/// and the panel shows a "not supported" note instead of a track list.
+pub fn fetch(bus_name: &str) -> Queue {
+    let Ok(conn) = Connection::new_session() else {
+        return Queue::unsupported();
+    };
+    let proxy = Proxy::new(bus_name, OBJECT_PATH, TIMEOUT, &conn);
+
+    let track_ids: Vec<DbusPath<'static>> =
+        match proxy.get(TRACK_LIST_IFACE, "Tracks") {
+            Ok(ids) => ids,
+            Err(_) => return Queue::unsupported(),
+        };
+    if track_ids.is_empty() {
+        return Queue {
+            supported: true,
+            tracks: Vec::new(),
+        };
+    }
+
+    let metas: Vec<PropMap> = match proxy.method_call(
+        TRACK_LIST_IFACE,
+        "GetTracksMetadata",
+        (track_ids.clone(),),
+    ) {
+        Ok((metas,)) => metas,
+        Err(_) => return Queue::unsupported(),
+    };
+
+    let tracks = track_ids
+        .into_iter()
+        .zip(metas.into_iter())
+        .map(|(id, meta)| QueueTrack {
+            id: id.to_string(),
+            title: string_prop(&meta, "xesam:title").unwrap_or_else(|| "Unknown".to_string()),
+            artist: string_list_prop(&meta, "xesam:artist")
+                .unwrap_or_else(|| "Unknown".to_string()),
+        })
+        .collect();
+
+    Queue {
+        supported: true,
+        tracks,
+    }
+}
+
+/// Calls `TrackList.GoTo` to jump playback to `track_id`.
+pub fn go_to(bus_name: &str, track_id: &str) -> bool {
+    let Ok(conn) = Connection::new_session() else {
+        return false;
+    };
+    let proxy = Proxy::new(bus_name, OBJECT_PATH, TIMEOUT, &conn);
+    let Ok(path) = DbusPath::new(track_id.to_string()) else {
+        return false;
+    };
+    proxy
+        .method_call::<(), _, _, _>(TRACK_LIST_IFACE, "GoTo", (path,))
+        .is_ok()
+}
+
+fn string_prop(meta: &PropMap, key: &str) -> Option<String> {
+    meta.get(key)?.as_str().map(|s| s.to_string())
+}
+
+fn string_list_prop(meta: &PropMap, key: &str) -> Option<String> {
+    let names: Vec<String> = meta
+        .get(key)?
+        .as_iter()?
+        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+        .collect();
+    if names.is_empty() {
+        None
+    } else {
+        Some(names.join(", "))
+    }
+}
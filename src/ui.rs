@@ -4,35 +4,106 @@ use ratatui::{
     layout::{Constraint, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Tabs},
+    widgets::{Block, Borders, Gauge, List, ListItem, Paragraph, Tabs},
     Frame,
 };
 
 use crate::app::App;
+use crate::config::Theme;
+
+/// Prefix drawn before the volume bar in `draw_controls`; also used to
+/// locate the bar's hit-test rect in `draw` so the two can't drift apart.
+const VOLUME_PREFIX: &str = "  Volume: [";
 
 fn format_duration(d: Duration) -> String {
     let secs = d.as_secs();
     format!("{}:{:02}", secs / 60, secs % 60)
 }
 
-pub fn draw(frame: &mut Frame, app: &App) {
+/// Splits the frame into the album art column, the main transport column,
+/// and the queue column. Shared by `draw` and `art_rect` so both agree on
+/// where the art cell is.
+fn columns(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::horizontal([
+        Constraint::Length(24),
+        Constraint::Min(40),
+        Constraint::Length(32),
+    ])
+    .split(area)
+}
+
+/// Returns the Rect reserved for the album art cell, for `main` to anchor
+/// cursor-positioned graphics escape sequences after drawing a frame.
+pub fn art_rect(area: Rect) -> Rect {
+    columns(area)[0]
+}
+
+pub fn draw(frame: &mut Frame, app: &mut App, theme: &Theme) {
+    let outer = columns(frame.area());
+
+    draw_art(frame, app, outer[0], theme);
+
     let chunks = Layout::vertical([
         Constraint::Length(3), // Player tabs
         Constraint::Length(5), // Track info
+        Constraint::Length(5), // Synced lyrics
         Constraint::Length(3), // Progress bar
         Constraint::Length(4), // Controls + volume
         Constraint::Length(3), // Help bar
     ])
-    .split(frame.area());
+    .split(outer[1]);
+
+    app.tabs_rect = chunks[0];
+    app.progress_rect = chunks[3];
+    // Second content line of the controls block is the volume bar; stash
+    // its inner rect so a click can be translated into a volume level.
+    // The bar starts one column inside the left border, after the
+    // "  Volume: [" prefix drawn by `draw_controls`.
+    app.volume_rect = Rect {
+        x: chunks[4].x + 1 + VOLUME_PREFIX.len() as u16,
+        y: chunks[4].y + 2,
+        width: chunks[4]
+            .width
+            .saturating_sub(VOLUME_PREFIX.len() as u16 + 2),
+        height: 1,
+    };
+
+    draw_player_tabs(frame, app, chunks[0], theme);
+    draw_track_info(frame, app, chunks[1], theme);
+    draw_lyrics(frame, app, chunks[2], theme);
+    draw_progress(frame, app, chunks[3], theme);
+    draw_controls(frame, app, chunks[4], theme);
+    draw_help(frame, chunks[5], theme);
+    draw_queue(frame, app, outer[2], theme);
+}
+
+fn draw_art(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default().title(" Art ").borders(Borders::ALL);
 
-    draw_player_tabs(frame, app, chunks[0]);
-    draw_track_info(frame, app, chunks[1]);
-    draw_progress(frame, app, chunks[2]);
-    draw_controls(frame, app, chunks[3]);
-    draw_help(frame, chunks[4]);
+    if !app.art_enabled {
+        let text = Paragraph::new("  Art off (i)").block(block);
+        frame.render_widget(text, area);
+        return;
+    }
+
+    match &app.art {
+        // A graphics protocol escape is emitted separately, right after the
+        // frame; leave the interior blank so it isn't painted over.
+        Some(art) if art.encoded.is_some() => frame.render_widget(block, area),
+        Some(_) => {
+            let text = Paragraph::new("\n   \u{1F3B5}")
+                .style(Style::default().fg(theme.accent))
+                .block(block);
+            frame.render_widget(text, area);
+        }
+        None => {
+            let text = Paragraph::new("  No art").block(block);
+            frame.render_widget(text, area);
+        }
+    }
 }
 
-fn draw_player_tabs(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_player_tabs(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     if app.player_names.is_empty() {
         let block = Block::default()
             .title(" playerctl-tui ")
@@ -53,16 +124,16 @@ fn draw_player_tabs(frame: &mut Frame, app: &App, area: Rect) {
                 .borders(Borders::ALL),
         )
         .select(app.selected_player)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.fg))
         .highlight_style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(theme.accent)
                 .add_modifier(Modifier::BOLD),
         );
     frame.render_widget(tabs, area);
 }
 
-fn draw_track_info(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_track_info(frame: &mut Frame, app: &App, area: Rect, _theme: &Theme) {
     let block = Block::default().borders(Borders::ALL);
     if app.player_names.is_empty() {
         let text = Paragraph::new("  Waiting for an MPRIS player...").block(block);
@@ -87,7 +158,100 @@ fn draw_track_info(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(text, area);
 }
 
-fn draw_progress(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_lyrics(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let block = Block::default().title(" Lyrics ").borders(Borders::ALL);
+
+    if !app.show_lyrics {
+        let text = Paragraph::new("  Lyrics hidden (press y to show)").block(block);
+        frame.render_widget(text, area);
+        return;
+    }
+
+    if app.lyrics.is_empty() {
+        let text = Paragraph::new("  No lyrics for this track").block(block);
+        frame.render_widget(text, area);
+        return;
+    }
+
+    // Window of lines centered on the active one, sized to fit the pane.
+    let visible = area.height.saturating_sub(2).max(1) as usize;
+    let active = app.active_lyric.unwrap_or(0);
+    let half = visible / 2;
+    let start = active.saturating_sub(half);
+    let end = (start + visible).min(app.lyrics.len());
+    let start = end.saturating_sub(visible);
+
+    let lines: Vec<Line> = app.lyrics[start..end]
+        .iter()
+        .enumerate()
+        .map(|(offset, (_, text))| {
+            let idx = start + offset;
+            if Some(idx) == app.active_lyric {
+                Line::from(Span::styled(
+                    format!("  {text}"),
+                    Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {text}"),
+                    Style::default().fg(theme.dim),
+                ))
+            }
+        })
+        .collect();
+
+    let text = Paragraph::new(lines).block(block);
+    frame.render_widget(text, area);
+}
+
+fn draw_queue(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
+    let title = if app.queue_focused {
+        " Queue [focused] "
+    } else {
+        " Queue "
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+
+    if !app.queue_supported {
+        let player = app
+            .player_names
+            .get(app.selected_player)
+            .map(String::as_str)
+            .unwrap_or("player");
+        let text = Paragraph::new(format!("  Queue not supported by {player}")).block(block);
+        frame.render_widget(text, area);
+        return;
+    }
+
+    if app.queue.is_empty() {
+        let text = Paragraph::new("  Queue is empty").block(block);
+        frame.render_widget(text, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .queue
+        .iter()
+        .enumerate()
+        .map(|(idx, track)| {
+            let line = Line::from(format!("{} - {}", track.title, track.artist));
+            if idx == app.queue_selected {
+                ListItem::new(line).style(
+                    Style::default()
+                        .fg(theme.accent)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ListItem::new(line)
+            }
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    frame.render_widget(list, area);
+}
+
+fn draw_progress(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     let ratio = if app.duration.as_secs_f64() > 0.0 {
         (app.position.as_secs_f64() / app.duration.as_secs_f64()).clamp(0.0, 1.0)
     } else {
@@ -100,13 +264,13 @@ fn draw_progress(frame: &mut Frame, app: &App, area: Rect) {
     );
     let gauge = Gauge::default()
         .block(Block::default().borders(Borders::ALL))
-        .gauge_style(Style::default().fg(Color::Cyan).bg(Color::DarkGray))
+        .gauge_style(Style::default().fg(theme.accent).bg(theme.dim))
         .ratio(ratio)
         .label(label);
     frame.render_widget(gauge, area);
 }
 
-fn draw_controls(frame: &mut Frame, app: &App, area: Rect) {
+fn draw_controls(frame: &mut Frame, app: &App, area: Rect, theme: &Theme) {
     let block = Block::default().borders(Borders::ALL);
 
     let status_icon = match app.playback_status.as_str() {
@@ -139,15 +303,15 @@ fn draw_controls(frame: &mut Frame, app: &App, area: Rect) {
             )),
         ]),
         Line::from(vec![
-            Span::styled("  Volume: [", Style::default().fg(Color::White)),
-            Span::styled(&vol_bar[..filled.min(bar_width)], Style::default().fg(Color::Magenta)),
+            Span::styled(VOLUME_PREFIX, Style::default().fg(theme.fg)),
+            Span::styled(&vol_bar[..filled.min(bar_width)], Style::default().fg(theme.accent)),
             Span::styled(
                 &vol_bar[filled.min(bar_width)..],
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.dim),
             ),
             Span::styled(
                 format!("] {}%", vol_pct),
-                Style::default().fg(Color::White),
+                Style::default().fg(theme.fg),
             ),
         ]),
     ];
@@ -155,24 +319,30 @@ fn draw_controls(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(text, area);
 }
 
-fn draw_help(frame: &mut Frame, area: Rect) {
+fn draw_help(frame: &mut Frame, area: Rect, theme: &Theme) {
     let help = Paragraph::new(Line::from(vec![
-        Span::styled(" q", Style::default().fg(Color::Cyan)),
+        Span::styled(" q", Style::default().fg(theme.accent)),
         Span::raw(":Quit "),
-        Span::styled("Space", Style::default().fg(Color::Cyan)),
+        Span::styled("Space", Style::default().fg(theme.accent)),
         Span::raw(":Play/Pause "),
-        Span::styled("n/p", Style::default().fg(Color::Cyan)),
+        Span::styled("n/p", Style::default().fg(theme.accent)),
         Span::raw(":Next/Prev "),
-        Span::styled("+/-", Style::default().fg(Color::Cyan)),
+        Span::styled("+/-", Style::default().fg(theme.accent)),
         Span::raw(":Vol "),
-        Span::styled("\u{2190}/\u{2192}", Style::default().fg(Color::Cyan)),
+        Span::styled("\u{2190}/\u{2192}", Style::default().fg(theme.accent)),
         Span::raw(":Seek "),
-        Span::styled("Tab", Style::default().fg(Color::Cyan)),
+        Span::styled("Tab", Style::default().fg(theme.accent)),
         Span::raw(":Player "),
-        Span::styled("l", Style::default().fg(Color::Cyan)),
+        Span::styled("l", Style::default().fg(theme.accent)),
         Span::raw(":Loop "),
-        Span::styled("s", Style::default().fg(Color::Cyan)),
-        Span::raw(":Shuffle"),
+        Span::styled("s", Style::default().fg(theme.accent)),
+        Span::raw(":Shuffle "),
+        Span::styled("y", Style::default().fg(theme.accent)),
+        Span::raw(":Lyrics "),
+        Span::styled("t", Style::default().fg(theme.accent)),
+        Span::raw(":Queue "),
+        Span::styled("i", Style::default().fg(theme.accent)),
+        Span::raw(":Art"),
     ]))
     .block(Block::default().borders(Borders::ALL));
     frame.render_widget(help, area);